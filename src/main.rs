@@ -1,17 +1,24 @@
 mod models;
+mod store;
 
 use anyhow::{Context, Result};
 use askama::Template;
 use axum::{
-    extract::State,
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
     response::{Html, IntoResponse},
     routing::get,
-    Router,
+    Json, Router,
 };
+use axum_server::tls_rustls::RustlsConfig;
 use models::{JobData, JobEntry};
-use std::{env, fs, sync::Arc};
+use std::{env, net::SocketAddr, sync::Arc};
+use store::{JobStore, JsonFileStore, NewJobEntry, SqliteStore, StoreError};
 use tower_http::services::ServeDir;
 
+const DEFAULT_RELOAD_INTERVAL_SECS: u64 = 5;
+const DB_JSON_PATH: &str = "db.json";
+
 #[derive(Template)]
 #[template(path = "index.html")]
 struct IndexTemplate<'a> {
@@ -20,43 +27,107 @@ struct IndexTemplate<'a> {
 }
 
 struct AppState {
-    jobs: Vec<JobEntry>,
+    store: Arc<dyn JobStore>,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
 
-    let job_data = load_jobs().context("Failed to load job data from db.json")?;
+    let store: Arc<dyn JobStore> = match env::var("STORE_BACKEND").as_deref() {
+        Ok("sqlite") => {
+            let database_url =
+                env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite://db.sqlite".to_string());
+            Arc::new(
+                SqliteStore::connect(&database_url)
+                    .await
+                    .with_context(|| format!("Failed to open SQLite store at {}", database_url))?,
+            )
+        }
+        _ => Arc::new(
+            JsonFileStore::load(DB_JSON_PATH).context("Failed to load job data from db.json")?,
+        ),
+    };
 
-    let state = Arc::new(AppState {
-        jobs: job_data.entries,
-    });
+    let state = Arc::new(AppState { store });
 
     let app = Router::new()
         .route("/", get(index_handler))
+        .route("/api/jobs", get(list_jobs).post(create_job))
+        .route(
+            "/api/jobs/:key",
+            get(get_job).put(update_job).delete(delete_job),
+        )
         .nest_service("/static", ServeDir::new("static"))
-        .with_state(state);
+        .with_state(state.clone());
+
+    tokio::spawn(watch_jobs(state));
 
     let addr = env::var("BIND_ADDR").unwrap_or_else(|_| "127.0.0.1:3000".to_string());
-    let listener = tokio::net::TcpListener::bind(&addr)
-        .await
-        .with_context(|| format!("Failed to bind to {}", addr))?;
 
-    tracing::info!("Server listening on http://{}", addr);
+    let tls_cert_path = env::var("TLS_CERT_PATH").ok();
+    let tls_key_path = env::var("TLS_KEY_PATH").ok();
+
+    match (tls_cert_path, tls_key_path) {
+        (Some(cert_path), Some(key_path)) => {
+            let socket_addr: SocketAddr = addr
+                .parse()
+                .with_context(|| format!("Invalid BIND_ADDR: {}", addr))?;
+
+            let tls_config = RustlsConfig::from_pem_file(&cert_path, &key_path)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to load TLS cert/key from {} / {}",
+                        cert_path, key_path
+                    )
+                })?;
+
+            tracing::info!("Server listening on https://{}", socket_addr);
+
+            axum_server::bind_rustls(socket_addr, tls_config)
+                .serve(app.into_make_service())
+                .await
+                .context("Server error")?;
+        }
+        (None, None) => {
+            let listener = tokio::net::TcpListener::bind(&addr)
+                .await
+                .with_context(|| format!("Failed to bind to {}", addr))?;
 
-    axum::serve(listener, app)
-        .await
-        .context("Server error")?;
+            tracing::info!("Server listening on http://{}", addr);
+
+            axum::serve(listener, app).await.context("Server error")?;
+        }
+        _ => {
+            anyhow::bail!(
+                "TLS_CERT_PATH and TLS_KEY_PATH must both be set to enable HTTPS (only one was provided)"
+            );
+        }
+    }
 
     Ok(())
 }
 
-async fn index_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+/// Negotiates on `Accept`: `application/json` returns the raw `JobData`,
+/// anything else (including no header) renders the HTML template as before.
+async fn index_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let jobs = match state.store.all().await {
+        Ok(jobs) => jobs,
+        Err(err) => return store_error_response(err),
+    };
+
+    if wants_json(&headers) {
+        return Json(JobData { entries: jobs }).into_response();
+    }
+
     let current_year = chrono::Datelike::year(&chrono::Local::now());
 
     let template = IndexTemplate {
-        jobs: &state.jobs,
+        jobs: &jobs,
         year: current_year,
     };
 
@@ -73,13 +144,102 @@ async fn index_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse
     }
 }
 
-fn load_jobs() -> Result<JobData> {
-    let data = fs::read_to_string("db.json").context("Could not read db.json")?;
-    parse_job_data(&data)
+fn wants_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("application/json"))
+}
+
+async fn list_jobs(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    match state.store.all().await {
+        Ok(jobs) => Json(JobData { entries: jobs }).into_response(),
+        Err(err) => store_error_response(err),
+    }
+}
+
+async fn get_job(State(state): State<Arc<AppState>>, Path(key): Path<u32>) -> impl IntoResponse {
+    match state.store.get(key).await {
+        Ok(job) => Json(job).into_response(),
+        Err(err) => store_error_response(err),
+    }
+}
+
+async fn create_job(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<NewJobEntry>,
+) -> impl IntoResponse {
+    match state.store.insert(payload).await {
+        Ok(job) => (StatusCode::CREATED, Json(job)).into_response(),
+        Err(err) => store_error_response(err),
+    }
+}
+
+async fn update_job(
+    State(state): State<Arc<AppState>>,
+    Path(key): Path<u32>,
+    Json(payload): Json<JobEntry>,
+) -> impl IntoResponse {
+    match state.store.update(key, payload).await {
+        Ok(job) => Json(job).into_response(),
+        Err(err) => store_error_response(err),
+    }
+}
+
+async fn delete_job(State(state): State<Arc<AppState>>, Path(key): Path<u32>) -> impl IntoResponse {
+    match state.store.delete(key).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(err) => store_error_response(err),
+    }
+}
+
+fn store_error_response(err: StoreError) -> axum::response::Response {
+    match err {
+        StoreError::NotFound => StatusCode::NOT_FOUND.into_response(),
+        StoreError::Conflict => StatusCode::CONFLICT.into_response(),
+        StoreError::Other(err) => {
+            tracing::error!("Store error: {}", err);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Polls `watch_path`'s mtime every `RELOAD_INTERVAL` seconds (default 5)
+/// and only asks the store to reload when it actually changed. Backends
+/// without a `watch_path` (e.g. `SqliteStore`) have nothing external to pick
+/// up, so this returns immediately instead of polling for no reason.
+async fn watch_jobs(state: Arc<AppState>) {
+    let Some(path) = state.store.watch_path().map(str::to_string) else {
+        return;
+    };
+
+    let interval_secs = env::var("RELOAD_INTERVAL")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_RELOAD_INTERVAL_SECS);
+
+    let mut last_modified = file_mtime(&path);
+
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(interval_secs)).await;
+
+        let modified = file_mtime(&path);
+        if modified == last_modified {
+            continue;
+        }
+        last_modified = modified;
+
+        if let Err(StoreError::Other(err)) = state.store.reload().await {
+            tracing::error!(
+                "Failed to reload store, keeping last good snapshot: {}",
+                err
+            );
+        }
+    }
 }
 
-fn parse_job_data(json: &str) -> Result<JobData> {
-    serde_json::from_str(json).context("Invalid JSON in db.json")
+fn file_mtime(path: &str) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
 }
 
 #[cfg(test)]
@@ -89,6 +249,74 @@ mod tests {
     use http_body_util::BodyExt;
     use tower::ServiceExt;
 
+    /// In-memory `JobStore` double used to exercise handlers without hitting
+    /// disk; `store.rs` has the contract tests that run against the real
+    /// `JsonFileStore`/`SqliteStore` backends.
+    struct TestStore {
+        jobs: tokio::sync::RwLock<Vec<JobEntry>>,
+    }
+
+    impl TestStore {
+        fn new(jobs: Vec<JobEntry>) -> Self {
+            Self {
+                jobs: tokio::sync::RwLock::new(jobs),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl JobStore for TestStore {
+        async fn all(&self) -> Result<Vec<JobEntry>, StoreError> {
+            Ok(self.jobs.read().await.clone())
+        }
+
+        async fn get(&self, key: u32) -> Result<JobEntry, StoreError> {
+            self.jobs
+                .read()
+                .await
+                .iter()
+                .find(|job| job.key == key)
+                .cloned()
+                .ok_or(StoreError::NotFound)
+        }
+
+        async fn insert(&self, entry: NewJobEntry) -> Result<JobEntry, StoreError> {
+            let mut jobs = self.jobs.write().await;
+            let key = jobs.iter().map(|job| job.key).max().unwrap_or(0) + 1;
+            let job = JobEntry {
+                key,
+                name: entry.name,
+                details: entry.details,
+                tools: entry.tools,
+                screen: entry.screen,
+                link: entry.link,
+            };
+            jobs.push(job.clone());
+            Ok(job)
+        }
+
+        async fn update(&self, key: u32, entry: JobEntry) -> Result<JobEntry, StoreError> {
+            let mut jobs = self.jobs.write().await;
+            let Some(index) = jobs.iter().position(|job| job.key == key) else {
+                return Err(StoreError::NotFound);
+            };
+            if entry.key != key && jobs.iter().any(|job| job.key == entry.key) {
+                return Err(StoreError::Conflict);
+            }
+            jobs[index] = entry.clone();
+            Ok(entry)
+        }
+
+        async fn delete(&self, key: u32) -> Result<(), StoreError> {
+            let mut jobs = self.jobs.write().await;
+            let Some(index) = jobs.iter().position(|job| job.key == key) else {
+                return Err(StoreError::NotFound);
+            };
+            jobs.remove(index);
+            Ok(())
+        }
+    }
+
     fn sample_job() -> JobEntry {
         JobEntry {
             key: 1,
@@ -100,68 +328,24 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_parse_valid_json() {
-        let json = r#"{
-            "entries": [{
-                "key": 1,
-                "name": "Test Company",
-                "details": "Test details",
-                "tools": "Rust",
-                "screen": "/test.png",
-                "link": "https://example.com"
-            }]
-        }"#;
-
-        let result = parse_job_data(json);
-        assert!(result.is_ok());
-
-        let job_data = result.unwrap();
-        assert_eq!(job_data.entries.len(), 1);
-        assert_eq!(job_data.entries[0].name, "Test Company");
-    }
-
-    #[test]
-    fn test_parse_multiple_entries() {
-        let json = r#"{
-            "entries": [
-                {"key": 1, "name": "Company A", "details": "A", "tools": "A", "screen": "/a.png", "link": "https://a.com"},
-                {"key": 2, "name": "Company B", "details": "B", "tools": "B", "screen": "/b.png", "link": "https://b.com"}
-            ]
-        }"#;
-
-        let result = parse_job_data(json);
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().entries.len(), 2);
-    }
-
-    #[test]
-    fn test_parse_empty_entries() {
-        let json = r#"{"entries": []}"#;
-
-        let result = parse_job_data(json);
-        assert!(result.is_ok());
-        assert!(result.unwrap().entries.is_empty());
-    }
+    fn app_with_jobs(jobs: Vec<JobEntry>) -> Router {
+        let state = Arc::new(AppState {
+            store: Arc::new(TestStore::new(jobs)),
+        });
 
-    #[test]
-    fn test_parse_invalid_json() {
-        let json = "not valid json";
-        let result = parse_job_data(json);
-        assert!(result.is_err());
+        Router::new()
+            .route("/", get(index_handler))
+            .route("/api/jobs", get(list_jobs).post(create_job))
+            .route(
+                "/api/jobs/:key",
+                get(get_job).put(update_job).delete(delete_job),
+            )
+            .with_state(state)
     }
 
-    #[test]
-    fn test_parse_missing_field() {
-        let json = r#"{
-            "entries": [{
-                "key": 1,
-                "name": "Test"
-            }]
-        }"#;
-
-        let result = parse_job_data(json);
-        assert!(result.is_err());
+    async fn body_json(response: axum::response::Response) -> serde_json::Value {
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
     }
 
     #[test]
@@ -194,13 +378,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_index_handler_returns_200() {
-        let state = Arc::new(AppState {
-            jobs: vec![sample_job()],
-        });
-
-        let app = Router::new()
-            .route("/", get(index_handler))
-            .with_state(state);
+        let app = app_with_jobs(vec![sample_job()]);
 
         let response = app
             .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
@@ -212,13 +390,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_index_handler_returns_html() {
-        let state = Arc::new(AppState {
-            jobs: vec![sample_job()],
-        });
-
-        let app = Router::new()
-            .route("/", get(index_handler))
-            .with_state(state);
+        let app = app_with_jobs(vec![sample_job()]);
 
         let response = app
             .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
@@ -233,12 +405,55 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_index_handler_empty_jobs() {
-        let state = Arc::new(AppState { jobs: vec![] });
+    async fn test_index_handler_html_branch_by_default() {
+        let app = app_with_jobs(vec![sample_job()]);
 
-        let app = Router::new()
-            .route("/", get(index_handler))
-            .with_state(state);
+        let response = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let content_type = response
+            .headers()
+            .get(axum::http::header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(content_type.starts_with("text/html"));
+    }
+
+    #[tokio::test]
+    async fn test_index_handler_json_branch_when_accept_json() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(axum::http::header::ACCEPT, "application/json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let content_type = response
+            .headers()
+            .get(axum::http::header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(content_type.starts_with("application/json"));
+
+        let json = body_json(response).await;
+        assert_eq!(json["entries"][0]["name"], "Test Company");
+    }
+
+    #[tokio::test]
+    async fn test_index_handler_empty_jobs() {
+        let app = app_with_jobs(vec![]);
 
         let response = app
             .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
@@ -250,11 +465,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_404_for_unknown_route() {
-        let state = Arc::new(AppState { jobs: vec![] });
-
-        let app = Router::new()
-            .route("/", get(index_handler))
-            .with_state(state);
+        let app = app_with_jobs(vec![]);
 
         let response = app
             .oneshot(
@@ -268,4 +479,232 @@ mod tests {
 
         assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
     }
+
+    #[tokio::test]
+    async fn test_list_jobs_returns_all_entries() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/jobs")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let json = body_json(response).await;
+        assert_eq!(json["entries"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_job_returns_404_for_unknown_key() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/jobs/999")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_job_returns_matching_entry() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/jobs/1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let json = body_json(response).await;
+        assert_eq!(json["name"], "Test Company");
+    }
+
+    #[tokio::test]
+    async fn test_create_job_allocates_next_key() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let payload = serde_json::json!({
+            "name": "New Co",
+            "details": "New details",
+            "tools": "Rust",
+            "screen": "/new.png",
+            "link": "https://new.example.com"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/jobs")
+                    .header("content-type", "application/json")
+                    .body(Body::from(payload.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CREATED);
+        let json = body_json(response).await;
+        assert_eq!(json["key"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_job_returns_400_for_malformed_body() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/jobs")
+                    .header("content-type", "application/json")
+                    .body(Body::from("not json"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_update_job_returns_404_for_unknown_key() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let mut updated = sample_job();
+        updated.name = "Renamed".to_string();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/api/jobs/999")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&updated).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_update_job_returns_200_on_success() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let mut updated = sample_job();
+        updated.name = "Renamed".to_string();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/api/jobs/1")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&updated).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let json = body_json(response).await;
+        assert_eq!(json["name"], "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_update_job_returns_400_for_malformed_body() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/api/jobs/1")
+                    .header("content-type", "application/json")
+                    .body(Body::from("not json"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_update_job_returns_409_on_duplicate_key() {
+        let mut second = sample_job();
+        second.key = 2;
+        let app = app_with_jobs(vec![sample_job(), second]);
+
+        let mut updated = sample_job();
+        updated.key = 2;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/api/jobs/1")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&updated).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_delete_job_returns_404_for_unknown_key() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri("/api/jobs/999")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_delete_job_returns_204_on_success() {
+        let app = app_with_jobs(vec![sample_job()]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri("/api/jobs/1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NO_CONTENT);
+    }
 }
@@ -0,0 +1,576 @@
+use crate::models::{JobData, JobEntry};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteConnectOptions;
+use std::fs;
+use std::str::FromStr;
+use tokio::sync::RwLock;
+
+/// Fields accepted when creating a job entry; `key` is always allocated by
+/// the store rather than taken from the caller.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NewJobEntry {
+    pub name: String,
+    pub details: String,
+    pub tools: String,
+    pub screen: String,
+    pub link: String,
+}
+
+/// Outcomes a [`JobStore`] can report that the HTTP layer maps to status
+/// codes, alongside the generic I/O/serialization failure case.
+#[derive(Debug)]
+pub enum StoreError {
+    NotFound,
+    Conflict,
+    Other(anyhow::Error),
+}
+
+impl From<anyhow::Error> for StoreError {
+    fn from(err: anyhow::Error) -> Self {
+        StoreError::Other(err)
+    }
+}
+
+/// Storage backend for job entries. Handlers go through this trait instead
+/// of touching `fs`/a database directly, so the backend can be swapped via
+/// `STORE_BACKEND` without changing call sites.
+#[async_trait]
+pub trait JobStore: Send + Sync {
+    async fn all(&self) -> Result<Vec<JobEntry>, StoreError>;
+    async fn get(&self, key: u32) -> Result<JobEntry, StoreError>;
+    async fn insert(&self, entry: NewJobEntry) -> Result<JobEntry, StoreError>;
+    async fn update(&self, key: u32, entry: JobEntry) -> Result<JobEntry, StoreError>;
+    async fn delete(&self, key: u32) -> Result<(), StoreError>;
+
+    /// Re-reads the backing store if it supports external changes (e.g. a
+    /// hand-edited `db.json`). Backends that are always authoritative, like
+    /// `SqliteStore`, can leave this as a no-op.
+    async fn reload(&self) -> Result<(), StoreError> {
+        Ok(())
+    }
+
+    /// Path to poll for external edits, if this backend has one worth
+    /// watching. Returning `None` (the default) tells the caller there's
+    /// nothing to poll, so backends like `SqliteStore` aren't woken up on a
+    /// schedule for no reason.
+    fn watch_path(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// The original single-file backend: the whole job list lives in memory
+/// behind a lock and is atomically written back to `path` on every mutation.
+pub struct JsonFileStore {
+    path: String,
+    jobs: RwLock<Vec<JobEntry>>,
+    /// Serializes insert/update/delete so two mutators can't stage
+    /// conflicting snapshots; `jobs` itself is only locked briefly to read
+    /// the current state and to swap in the new one, so the disk write in
+    /// between never blocks unrelated reads.
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl JsonFileStore {
+    pub fn new(path: impl Into<String>, jobs: Vec<JobEntry>) -> Self {
+        Self {
+            path: path.into(),
+            jobs: RwLock::new(jobs),
+            write_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Reads and parses `path`, failing if it's missing or malformed.
+    pub fn load(path: impl Into<String>) -> Result<Self> {
+        let path = path.into();
+        let job_data = Self::read(&path)?;
+        Ok(Self::new(path, job_data.entries))
+    }
+
+    fn read(path: &str) -> Result<JobData> {
+        let data = fs::read_to_string(path).with_context(|| format!("Could not read {}", path))?;
+        serde_json::from_str(&data).with_context(|| format!("Invalid JSON in {}", path))
+    }
+
+    /// Serializes `jobs` and atomically replaces `self.path` via a temp-file
+    /// write plus rename, so a crash mid-write can't corrupt the store. The
+    /// actual write runs on the blocking thread pool so a slow disk doesn't
+    /// stall unrelated async work on this runtime; the caller's `write_lock`
+    /// stays held until the write finishes.
+    async fn persist(&self, jobs: &[JobEntry]) -> Result<()> {
+        let job_data = JobData {
+            entries: jobs.to_vec(),
+        };
+        let serialized =
+            serde_json::to_string_pretty(&job_data).context("Failed to serialize job data")?;
+
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let tmp_path = format!("{}.tmp", path);
+            fs::write(&tmp_path, serialized)
+                .with_context(|| format!("Failed to write {}", tmp_path))?;
+            fs::rename(&tmp_path, &path).with_context(|| format!("Failed to replace {}", path))
+        })
+        .await
+        .context("Persist task panicked")??;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl JobStore for JsonFileStore {
+    async fn all(&self) -> Result<Vec<JobEntry>, StoreError> {
+        Ok(self.jobs.read().await.clone())
+    }
+
+    async fn get(&self, key: u32) -> Result<JobEntry, StoreError> {
+        self.jobs
+            .read()
+            .await
+            .iter()
+            .find(|job| job.key == key)
+            .cloned()
+            .ok_or(StoreError::NotFound)
+    }
+
+    async fn insert(&self, entry: NewJobEntry) -> Result<JobEntry, StoreError> {
+        let _guard = self.write_lock.lock().await;
+
+        let jobs = self.jobs.read().await.clone();
+        let key = jobs.iter().map(|job| job.key).max().unwrap_or(0) + 1;
+
+        let job = JobEntry {
+            key,
+            name: entry.name,
+            details: entry.details,
+            tools: entry.tools,
+            screen: entry.screen,
+            link: entry.link,
+        };
+
+        let mut staged = jobs;
+        staged.push(job.clone());
+        self.persist(&staged).await?;
+        *self.jobs.write().await = staged;
+
+        Ok(job)
+    }
+
+    async fn update(&self, key: u32, entry: JobEntry) -> Result<JobEntry, StoreError> {
+        let _guard = self.write_lock.lock().await;
+
+        let jobs = self.jobs.read().await.clone();
+        let Some(index) = jobs.iter().position(|job| job.key == key) else {
+            return Err(StoreError::NotFound);
+        };
+        if entry.key != key && jobs.iter().any(|job| job.key == entry.key) {
+            return Err(StoreError::Conflict);
+        }
+
+        let mut staged = jobs;
+        staged[index] = entry.clone();
+        self.persist(&staged).await?;
+        *self.jobs.write().await = staged;
+
+        Ok(entry)
+    }
+
+    async fn delete(&self, key: u32) -> Result<(), StoreError> {
+        let _guard = self.write_lock.lock().await;
+
+        let jobs = self.jobs.read().await.clone();
+        let Some(index) = jobs.iter().position(|job| job.key == key) else {
+            return Err(StoreError::NotFound);
+        };
+
+        let mut staged = jobs;
+        staged.remove(index);
+        self.persist(&staged).await?;
+        *self.jobs.write().await = staged;
+
+        Ok(())
+    }
+
+    async fn reload(&self) -> Result<(), StoreError> {
+        let job_data = Self::read(&self.path)?;
+        *self.jobs.write().await = job_data.entries;
+        Ok(())
+    }
+
+    fn watch_path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+}
+
+/// Embedded-database backend for deployments that have outgrown rewriting
+/// one `db.json` on every mutation.
+pub struct SqliteStore {
+    pool: sqlx::SqlitePool,
+}
+
+impl SqliteStore {
+    /// Connects to `database_url`, creating the `jobs` table if needed, and
+    /// imports an existing `db.json` on first run (an empty table) so
+    /// switching `STORE_BACKEND` doesn't lose existing entries.
+    pub async fn connect(database_url: &str) -> Result<Self> {
+        let options = SqliteConnectOptions::from_str(database_url)
+            .with_context(|| format!("Invalid sqlite URL: {}", database_url))?
+            .create_if_missing(true);
+
+        let pool = sqlx::SqlitePool::connect_with(options)
+            .await
+            .with_context(|| format!("Failed to connect to {}", database_url))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS jobs (
+                key INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                details TEXT NOT NULL,
+                tools TEXT NOT NULL,
+                screen TEXT NOT NULL,
+                link TEXT NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .context("Failed to create jobs table")?;
+
+        let store = Self { pool };
+        store.import_json_if_empty("db.json").await?;
+        Ok(store)
+    }
+
+    async fn import_json_if_empty(&self, db_json_path: &str) -> Result<()> {
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM jobs")
+            .fetch_one(&self.pool)
+            .await
+            .context("Failed to count existing jobs")?;
+        if count > 0 {
+            return Ok(());
+        }
+
+        let Ok(data) = fs::read_to_string(db_json_path) else {
+            return Ok(());
+        };
+        let job_data: JobData = serde_json::from_str(&data)
+            .with_context(|| format!("Invalid JSON in {}", db_json_path))?;
+
+        for entry in job_data.entries {
+            self.insert_raw(&entry).await?;
+        }
+        tracing::info!("Imported existing {} into SqliteStore", db_json_path);
+
+        Ok(())
+    }
+
+    async fn insert_raw(&self, entry: &JobEntry) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO jobs (key, name, details, tools, screen, link) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(entry.key)
+        .bind(&entry.name)
+        .bind(&entry.details)
+        .bind(&entry.tools)
+        .bind(&entry.screen)
+        .bind(&entry.link)
+        .execute(&self.pool)
+        .await
+        .context("Failed to insert job row")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl JobStore for SqliteStore {
+    async fn all(&self) -> Result<Vec<JobEntry>, StoreError> {
+        let jobs: Vec<JobEntry> =
+            sqlx::query_as("SELECT key, name, details, tools, screen, link FROM jobs ORDER BY key")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|err| StoreError::Other(err.into()))?;
+
+        Ok(jobs)
+    }
+
+    async fn get(&self, key: u32) -> Result<JobEntry, StoreError> {
+        sqlx::query_as("SELECT key, name, details, tools, screen, link FROM jobs WHERE key = ?")
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|err| StoreError::Other(err.into()))?
+            .ok_or(StoreError::NotFound)
+    }
+
+    async fn insert(&self, entry: NewJobEntry) -> Result<JobEntry, StoreError> {
+        // A separate `SELECT MAX(key)` followed by an `INSERT` would let two
+        // concurrent requests read the same max and race on the same key.
+        // Folding the max into the `INSERT ... SELECT` keeps the read and the
+        // write in one statement, which SQLite executes atomically.
+        let result = sqlx::query(
+            "INSERT INTO jobs (key, name, details, tools, screen, link)
+             SELECT COALESCE(MAX(key), 0) + 1, ?, ?, ?, ?, ? FROM jobs",
+        )
+        .bind(&entry.name)
+        .bind(&entry.details)
+        .bind(&entry.tools)
+        .bind(&entry.screen)
+        .bind(&entry.link)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| StoreError::Other(err.into()))?;
+
+        let job = JobEntry {
+            key: result.last_insert_rowid() as u32,
+            name: entry.name,
+            details: entry.details,
+            tools: entry.tools,
+            screen: entry.screen,
+            link: entry.link,
+        };
+
+        Ok(job)
+    }
+
+    async fn update(&self, key: u32, entry: JobEntry) -> Result<JobEntry, StoreError> {
+        // Checking for a conflicting `entry.key` with a separate `SELECT`
+        // before the `UPDATE` has the same TOCTOU window as a separate
+        // `MAX(key)` lookup in `insert`: two concurrent renames to the same new key could
+        // both pass the check. The `NOT EXISTS` guard folds that check into
+        // the `UPDATE` itself so the decision is made atomically.
+        let result = sqlx::query(
+            "UPDATE jobs
+             SET key = ?, name = ?, details = ?, tools = ?, screen = ?, link = ?
+             WHERE key = ?
+               AND NOT EXISTS (SELECT 1 FROM jobs WHERE key = ? AND key != ?)",
+        )
+        .bind(entry.key)
+        .bind(&entry.name)
+        .bind(&entry.details)
+        .bind(&entry.tools)
+        .bind(&entry.screen)
+        .bind(&entry.link)
+        .bind(key)
+        .bind(entry.key)
+        .bind(key)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| StoreError::Other(err.into()))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(entry);
+        }
+
+        // The atomic UPDATE above didn't touch a row; figure out which of
+        // its two guards failed so the caller gets the right status code.
+        if self.get(key).await.is_err() {
+            return Err(StoreError::NotFound);
+        }
+        Err(StoreError::Conflict)
+    }
+
+    async fn delete(&self, key: u32) -> Result<(), StoreError> {
+        let result = sqlx::query("DELETE FROM jobs WHERE key = ?")
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| StoreError::Other(err.into()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(StoreError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn sample_job(key: u32) -> JobEntry {
+        JobEntry {
+            key,
+            name: format!("Company {}", key),
+            details: "Some details".to_string(),
+            tools: "Rust".to_string(),
+            screen: "/shot.png".to_string(),
+            link: "https://example.com".to_string(),
+        }
+    }
+
+    fn new_entry() -> NewJobEntry {
+        NewJobEntry {
+            name: "New Co".to_string(),
+            details: "New details".to_string(),
+            tools: "Rust, Axum".to_string(),
+            screen: "/new.png".to_string(),
+            link: "https://new.example.com".to_string(),
+        }
+    }
+
+    /// Exercises the full CRUD contract against whichever `JobStore` is
+    /// handed in, so `JsonFileStore` and `SqliteStore` are held to the same
+    /// behavior.
+    async fn assert_crud_contract(store: &dyn JobStore) {
+        assert_eq!(store.all().await.unwrap(), vec![sample_job(1)]);
+
+        let created = store.insert(new_entry()).await.unwrap();
+        assert_eq!(created.key, 2);
+        assert_eq!(store.all().await.unwrap().len(), 2);
+
+        let fetched = store.get(2).await.unwrap();
+        assert_eq!(fetched.name, "New Co");
+
+        assert!(matches!(
+            store.get(999).await.unwrap_err(),
+            StoreError::NotFound
+        ));
+
+        let mut updated = fetched.clone();
+        updated.name = "Renamed Co".to_string();
+        let result = store.update(2, updated).await.unwrap();
+        assert_eq!(result.name, "Renamed Co");
+
+        let mut conflicting = store.get(2).await.unwrap();
+        conflicting.key = 1;
+        assert!(matches!(
+            store.update(2, conflicting).await.unwrap_err(),
+            StoreError::Conflict
+        ));
+
+        assert!(matches!(
+            store.update(999, sample_job(999)).await.unwrap_err(),
+            StoreError::NotFound
+        ));
+
+        store.delete(2).await.unwrap();
+        assert_eq!(store.all().await.unwrap().len(), 1);
+        assert!(matches!(
+            store.delete(2).await.unwrap_err(),
+            StoreError::NotFound
+        ));
+    }
+
+    fn unique_temp_path(name: &str) -> String {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+        std::env::temp_dir()
+            .join(format!(
+                "rust_persona_{}_{}_{}.json",
+                name,
+                std::process::id(),
+                n
+            ))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_crud_contract() {
+        let path = unique_temp_path("json_store");
+        let store = JsonFileStore::new(path.clone(), vec![sample_job(1)]);
+
+        assert_crud_contract(&store).await;
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(format!("{}.tmp", path));
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_insert_rolls_back_on_persist_failure() {
+        // A path under a directory that doesn't exist makes every write fail,
+        // so `persist` always errors without touching anything on disk.
+        let path = format!(
+            "{}/missing-dir/db.json",
+            std::env::temp_dir().to_string_lossy()
+        );
+        let store = JsonFileStore::new(path, vec![sample_job(1)]);
+
+        let err = store.insert(new_entry()).await.unwrap_err();
+        assert!(matches!(err, StoreError::Other(_)));
+
+        // The failed write must not have left the in-memory copy mutated.
+        assert_eq!(store.all().await.unwrap(), vec![sample_job(1)]);
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_reload_picks_up_external_edit() {
+        let path = unique_temp_path("json_reload");
+        fs::write(
+            &path,
+            serde_json::to_string(&JobData {
+                entries: vec![sample_job(1)],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        let store = JsonFileStore::load(&path).unwrap();
+        assert_eq!(store.all().await.unwrap().len(), 1);
+
+        fs::write(
+            &path,
+            serde_json::to_string(&JobData {
+                entries: vec![sample_job(1), sample_job(2)],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        store.reload().await.unwrap();
+        assert_eq!(store.all().await.unwrap().len(), 2);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_json_file_store_reload_keeps_last_good_snapshot_on_invalid_json() {
+        let path = unique_temp_path("json_reload_invalid");
+        fs::write(
+            &path,
+            serde_json::to_string(&JobData {
+                entries: vec![sample_job(1)],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        let store = JsonFileStore::load(&path).unwrap();
+        assert_eq!(store.all().await.unwrap(), vec![sample_job(1)]);
+
+        fs::write(&path, "not valid json").unwrap();
+
+        let err = store.reload().await.unwrap_err();
+        assert!(matches!(err, StoreError::Other(_)));
+
+        // The failed reload must not have clobbered the last good snapshot.
+        assert_eq!(store.all().await.unwrap(), vec![sample_job(1)]);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_crud_contract() {
+        let store = SqliteStore::connect("sqlite::memory:").await.unwrap();
+        store.insert_raw(&sample_job(1)).await.unwrap();
+
+        assert_crud_contract(&store).await;
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_creates_missing_db_file() {
+        let path = unique_temp_path("sqlite_store");
+        let _ = fs::remove_file(&path);
+
+        let database_url = format!("sqlite://{}", path);
+        let store = SqliteStore::connect(&database_url)
+            .await
+            .expect("connect should create the missing db file");
+        assert_eq!(store.all().await.unwrap(), vec![]);
+
+        let _ = fs::remove_file(&path);
+    }
+}